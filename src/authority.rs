@@ -1,6 +1,17 @@
-use crate::{coder::{Decoder, Encoder}, err::Error, ip, statics};
+use crate::{coder::{Decoder, Encoder}, err::Error, ip, statics, ParseOptions};
 
-#[derive(Debug)]
+use std::str::FromStr;
+
+/// The kind of a host as distinguished by RFC 3986 3.2.2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HostKind {
+    IpV4,
+    IpV6,
+    IpVFuture,
+    RegName,
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct Authority {
     pub userinfo: Option<String>,
     pub host: Option<String>,
@@ -13,11 +24,61 @@ impl PartialEq for Authority {
     }
 }
 
+// an authority without userinfo, host and port ("", "@", ":") is an error
+impl FromStr for Authority {
+    type Err = Error;
+
+    fn from_str(auth_string: &str) -> Result<Self, Self::Err> {
+        Self::parse(auth_string)?.ok_or(Error::EmptyAuthority)
+    }
+}
+
+// the string form "user@host:port", an empty authority is ""
+#[cfg(feature = "serde")]
+impl serde::Serialize for Authority {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let auth_string = self.stringify().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&auth_string.unwrap_or_default())
+    }
+}
+
+// parsed like 'parse', "" is an empty authority
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Authority {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let auth_string = <String as serde::Deserialize>::deserialize(deserializer)?;
+        let authority = Self::parse(&auth_string).map_err(serde::de::Error::custom)?;
+        Ok(authority.unwrap_or_default())
+    }
+}
+
+// compares host and port, the userinfo is ignored
+impl PartialEq<(&str, Option<u16>)> for Authority {
+    fn eq(&self, other: &(&str, Option<u16>)) -> bool {
+        self.host.as_deref() == Some(other.0) && self.port == other.1
+    }
+}
+
 impl Authority {
+    /// An empty userinfo ("@example.com") and an empty port ("example.com:") are normalized away.
+    ///
     /// # Errors
     ///
     /// Will return 'Error' if given string contains characters that are not valid in their respctive parts.
     pub fn parse(auth_string: &str) -> Result<Option<Self>, Error> {
+        Self::parse_with_options(auth_string, &ParseOptions::default())
+    }
+
+    /// # Errors
+    ///
+    /// Will return 'Error' if given string contains characters that are not valid in their respctive parts,
+    /// or if it breaks a limit set in the options.
+    pub fn parse_with_options(auth_string: &str, options: &ParseOptions) -> Result<Option<Self>, Error> {
+        Ok(Self::parse_with_encoded(auth_string, options)?.map(|(decoded, _)| decoded))
+    }
+
+    // parses like 'parse_with_options' and also returns the authority with userinfo and host still percent encoded
+    pub(crate) fn parse_with_encoded(auth_string: &str, options: &ParseOptions) -> Result<Option<(Self, Self)>, Error> {
         if auth_string.is_empty() {
             return Ok(None);
         }
@@ -29,6 +90,10 @@ impl Authority {
             Some(rest) => Self::split_host(rest)?,
         };
 
+        if options.forbid_userinfo && userinfo.is_some() {
+            return Err(Error::UserinfoNotAllowed);
+        }
+
         let parsed_userinfo: Option<String> = match userinfo {
             None => None,
             Some(useri) => Some(Self::parse_userinfo(useri)?),
@@ -36,7 +101,7 @@ impl Authority {
 
         let parsed_host: Option<String> = match host {
             None => None,
-            Some(h) => Some(Self::parse_host(h)?),
+            Some(h) => Some(Self::parse_host(h, options)?),
         };
 
         let parsed_port: Option<u16> = match port {
@@ -44,16 +109,40 @@ impl Authority {
             Some(p) => Some(Self::parse_port(p)?),
         };
 
+        if options.forbid_port_zero && parsed_port == Some(0) {
+            return Err(Error::ParsePortError);
+        }
+
         match (&parsed_userinfo, &parsed_host, &parsed_port) {
             (None, None, None) => Ok(None),
-            (_, _, _) => Ok(Some(Authority {
-                userinfo: parsed_userinfo,
-                host: parsed_host,
-                port: parsed_port,
-            })),
+            (_, _, _) => Ok(Some((
+                Authority {
+                    userinfo: parsed_userinfo,
+                    host: parsed_host,
+                    port: parsed_port,
+                },
+                Authority {
+                    userinfo: userinfo.map(String::from),
+                    host: host.map(String::from),
+                    port: parsed_port,
+                },
+            ))),
         }
     }
 
+    // the authority with userinfo and host percent encoded like 'stringify' writes them
+    pub(crate) fn encode(&self) -> Result<Self, Error> {
+        let encode = |part: &str, viable_chars| Encoder::new(part.chars().collect(), viable_chars).encode();
+        Ok(Authority {
+            userinfo: self.userinfo.as_deref().map(|ui| encode(ui, &statics::USER_INFO)).transpose()?,
+            host: match self.host.as_deref() {
+                Some(ho) if !ho.starts_with('[') => Some(encode(ho, &statics::REG_NAME)?),
+                ho => ho.map(String::from),
+            },
+            port: self.port,
+        })
+    }
+
     fn split_userinfo(auth_string: &str) -> (Option<&str>, Option<&str>) {
         match auth_string.split_once('@') {
             None => (None, Some(auth_string)),
@@ -112,7 +201,7 @@ impl Authority {
         }
     }
 
-    fn parse_userinfo(user_info: &str) -> Result<String, Error> {
+    pub(crate) fn parse_userinfo(user_info: &str) -> Result<String, Error> {
         // build decoder
         let chars: Vec<char> = user_info.chars().collect();
         let mut userinfo_decoder = Decoder::new(chars, &statics::USER_INFO);
@@ -127,10 +216,16 @@ impl Authority {
         }
     }
 
-    fn parse_host(host: &str) -> Result<String, Error> {
+    pub(crate) fn parse_host(host: &str, options: &ParseOptions) -> Result<String, Error> {
+        if let Some(max) = options.max_host_length {
+            if host.len() > max {
+                return Err(Error::HostTooLong);
+            }
+        }
+
         // check what kind of host is given
         let starts_with = host.starts_with('[');
-        let ends_with = host.starts_with('[');
+        let ends_with = host.ends_with(']');
 
         // if a IP-literal is given it needs to start with "[" and ends with "]"
         if (starts_with && !ends_with) || (!starts_with && ends_with) {
@@ -145,7 +240,7 @@ impl Authority {
                 return Err(Error::IllegaHostDefinition);
             }
 
-            let host_stripped = &host[1..host.len() - 2];
+            let host_stripped = &host[1..host.len() - 1];
 
             // IPvFuture
             if host_stripped.starts_with('v') {
@@ -167,7 +262,7 @@ impl Authority {
         // The syntax rule for host is ambiguous because it does not completely
         // distinguish between an IPv4address and a reg-name.
         
-        let chars: Vec<char> = host.chars().into_iter().collect();
+        let chars: Vec<char> = host.chars().collect();
         let mut decoder = Decoder::new(chars, &statics::REG_NAME);
         match decoder.decode() {
             Ok(result) => Ok(result),
@@ -182,6 +277,10 @@ impl Authority {
     }
 
     fn parse_port(port_str: &str) -> Result<u16, Error> {
+        // the port only allows digits, an encoded digit ("%38") is still not a digit
+        if port_str.contains('%') {
+            return Err(Error::PortPercentEncoded);
+        }
         match port_str.parse::<u16>() {
             Err(_) => Err(Error::ParsePortError),
             Ok(port) => Ok(port),
@@ -198,33 +297,54 @@ impl Authority {
 
         if self.port.is_none() && self.host.is_none() && self.userinfo .is_none() {
             return Ok(None);
-        };
+        }
 
         if let Some(ui) = &self.userinfo {
-            let chars:Vec<char> = ui.chars().into_iter().collect();
+            let chars:Vec<char> = ui.chars().collect();
             encoder = Encoder::new(chars, &statics::USER_INFO);
             output.push_str(&encoder.encode()?);
             output.push('@');
-        };
+        }
 
         if let Some(ho) = &self.host {
             if ho.starts_with('[') {
                 output.push_str(ho);
             } else {
-                let chars:Vec<char> = ho.chars().into_iter().collect();
+                let chars:Vec<char> = ho.chars().collect();
                 encoder = Encoder::new(chars, &statics::REG_NAME);
                 output.push_str(&encoder.encode()?);
             }
-        };
+        }
 
         if let Some(po) = self.port {
             output.push(':');
             output.push_str(&po.to_string());
-        };
+        }
 
         Ok(Some(output))
     }
 
+    // stringify without encoding, for an Authority that is already percent encoded
+    pub(crate) fn stringify_encoded(&self) -> Option<String> {
+        if self.port.is_none() && self.host.is_none() && self.userinfo.is_none() {
+            return None;
+        }
+
+        let mut output = String::new();
+        if let Some(ui) = &self.userinfo {
+            output.push_str(ui);
+            output.push('@');
+        }
+        if let Some(ho) = &self.host {
+            output.push_str(ho);
+        }
+        if let Some(po) = self.port {
+            output.push(':');
+            output.push_str(&po.to_string());
+        }
+        Some(output)
+    }
+
     #[must_use]
     pub fn userinfo(&self) -> Option<&str> {
         self.userinfo.as_deref()
@@ -239,13 +359,36 @@ impl Authority {
     pub fn port(&self) -> Option<u16> {
         self.port
     }
+
+    /// Returns the kind of the host, an IP-literal in "[]" is an IPv6 or `IPvFuture` address.
+    /// A host that is a valid IPv4 address is one, even though it is also a valid reg-name.
+    #[must_use]
+    pub fn host_kind(&self) -> Option<HostKind> {
+        let host = self.host.as_deref()?;
+        Some(match host.strip_prefix('[') {
+            Some(literal) if literal.starts_with('v') || literal.starts_with('V') => HostKind::IpVFuture,
+            Some(_) => HostKind::IpV6,
+            None if ip::is_valid_ip_v4(host) => HostKind::IpV4,
+            None => HostKind::RegName,
+        })
+    }
+
+    /// Returns true if the host is a valid IPv4 address like "1.2.3.4", the host is still stored as given.
+    /// A reg-name that only starts like one ("1.2.3.4.com") is no IPv4 address.
+    #[must_use]
+    pub fn is_ipv4_host(&self) -> bool {
+        self.host.as_deref().is_some_and(ip::is_valid_ip_v4)
+    }
 }
 
 #[cfg(test)]
+// the tests from before the pedantic lints were enabled keep their original style
+#[allow(clippy::explicit_iter_loop, clippy::semicolon_if_nothing_returned)]
 mod tests {
 
-    use crate::{Error, TestCase};
-    use super::Authority;
+    use crate::{Error, ParseOptions, TestCase};
+    use super::{Authority, HostKind};
+    use std::collections::HashSet;
 
     #[test]
     fn parse_ok() {
@@ -258,6 +401,22 @@ mod tests {
                     port: None,
                 }),
             },
+            TestCase {
+                case: Authority::parse("user:pass@example.com").unwrap(),
+                expected: Some(Authority {
+                    userinfo: Some(String::from("user:pass")),
+                    host: Some(String::from("example.com")),
+                    port: None,
+                }),
+            },
+            TestCase {
+                case: Authority::parse("user:@[2001:db8:3333:4444:5555:6666:7777:8888]").unwrap(),
+                expected: Some(Authority {
+                    userinfo: Some(String::from("user:")),
+                    host: Some(String::from("[2001:db8:3333:4444:5555:6666:7777:8888]")),
+                    port: None,
+                }),
+            },
             TestCase {
                 case: Authority::parse("user@example.com").unwrap(),
                 expected: Some(Authority {
@@ -356,6 +515,14 @@ mod tests {
                     port: None,
                 }),
             },
+            TestCase {
+                case: Authority::parse("[v1a.abc]").unwrap(),
+                expected: Some(Authority {
+                    userinfo: None,
+                    host: Some(String::from("[v1a.abc]")),
+                    port: None,
+                }),
+            },
             TestCase {
                 case: Authority::parse("[v7.aaaa:bbbb:cccc::]:8080").unwrap(),
                 expected: Some(Authority {
@@ -414,6 +581,23 @@ mod tests {
                     port: Some(8080),
                 }),
             },
+            // percent encoded delimiters are no delimiters, only the literal '@' and ':' split
+            TestCase {
+                case: Authority::parse("us%40er@host").unwrap(),
+                expected: Some(Authority {
+                    userinfo: Some(String::from("us@er")),
+                    host: Some(String::from("host")),
+                    port: None,
+                }),
+            },
+            TestCase {
+                case: Authority::parse("us%40er@ho%3Ast:8080").unwrap(),
+                expected: Some(Authority {
+                    userinfo: Some(String::from("us@er")),
+                    host: Some(String::from("ho:st")),
+                    port: Some(8080),
+                }),
+            },
         ];
 
         for test in tests.iter() {
@@ -421,13 +605,210 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_host_length() {
+        let options = ParseOptions { max_host_length: Some(255), ..ParseOptions::default() };
+
+        let tests = [
+            TestCase {
+                case: Authority::parse_with_options(&"a".repeat(255), &options).map(|auth| auth.and_then(|a| a.host)),
+                expected: Ok(Some("a".repeat(255))),
+            },
+            TestCase {
+                case: Authority::parse_with_options(&"a".repeat(256), &options).map(|auth| auth.and_then(|a| a.host)),
+                expected: Err(Error::HostTooLong),
+            },
+            TestCase {
+                case: Authority::parse_with_options(&format!("user@{}:8080", "a".repeat(256)), &options).map(|auth| auth.and_then(|a| a.host)),
+                expected: Err(Error::HostTooLong),
+            },
+            // without the option the length isn't limited
+            TestCase {
+                case: Authority::parse(&"a".repeat(256)).map(|auth| auth.and_then(|a| a.host)),
+                expected: Ok(Some("a".repeat(256))),
+            },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+    }
+
+    #[test]
+    fn parse_forbid_userinfo() {
+        let forbid_userinfo = ParseOptions {
+            forbid_userinfo: true,
+            ..ParseOptions::default()
+        };
+
+        let tests = [
+            TestCase {
+                case: Authority::parse_with_options("user@host", &forbid_userinfo),
+                expected: Err(Error::UserinfoNotAllowed),
+            },
+            TestCase {
+                case: Authority::parse_with_options("user@host", &ParseOptions::default()),
+                expected: Ok(Some(Authority {
+                    userinfo: Some(String::from("user")),
+                    host: Some(String::from("host")),
+                    port: None,
+                })),
+            },
+            // an empty userinfo is no userinfo
+            TestCase {
+                case: Authority::parse_with_options("@host:80", &forbid_userinfo),
+                expected: Ok(Some(Authority {
+                    userinfo: None,
+                    host: Some(String::from("host")),
+                    port: Some(80),
+                })),
+            },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+    }
+
+    #[test]
+    fn parse_forbid_port_zero() {
+        let options = ParseOptions { forbid_port_zero: true, ..ParseOptions::default() };
+
+        let tests = [
+            TestCase { case: Authority::parse("example.com:0").map(|auth| auth.and_then(|a| a.port)), expected: Ok(Some(0)) },
+            TestCase { case: Authority::parse_with_options("example.com:0", &options).map(|auth| auth.and_then(|a| a.port)), expected: Err(Error::ParsePortError) },
+            TestCase { case: Authority::parse_with_options("example.com:00", &options).map(|auth| auth.and_then(|a| a.port)), expected: Err(Error::ParsePortError) },
+            TestCase { case: Authority::parse_with_options("example.com:80", &options).map(|auth| auth.and_then(|a| a.port)), expected: Ok(Some(80)) },
+            TestCase { case: Authority::parse_with_options("example.com:", &options).map(|auth| auth.and_then(|a| a.port)), expected: Ok(None) },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+    }
+
+    #[test]
+    fn stringify_empty_userinfo() {
+        let tests = [
+            TestCase{ case: Authority::parse("@example.com:8080").unwrap().unwrap().stringify(), expected: Ok(Some(String::from("example.com:8080"))) },
+            TestCase{ case: Authority::parse("@example.com:").unwrap().unwrap().stringify(), expected: Ok(Some(String::from("example.com"))) },
+            TestCase{ case: Authority::parse("@:8080").unwrap().unwrap().stringify(), expected: Ok(Some(String::from(":8080"))) },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+    }
+
+    #[test]
+    fn stringify_userinfo_only() {
+        let authority = Authority::parse("user@").unwrap().unwrap();
+        assert_eq!(authority.userinfo(), Some("user"));
+        assert_eq!(authority.host(), None);
+        assert_eq!(authority.port(), None);
+        assert_eq!(authority.stringify(), Ok(Some(String::from("user@"))));
+
+        let tests = [
+            TestCase{ case: Authority::parse("user@").unwrap().unwrap().stringify(), expected: Ok(Some(String::from("user@"))) },
+            TestCase{ case: Authority::parse("us%20er:pw@").unwrap().unwrap().stringify(), expected: Ok(Some(String::from("us%20er:pw@"))) },
+            TestCase{
+                case: Ok(Authority::parse_with_encoded("us%20er@", &ParseOptions::default()).unwrap().and_then(|(_, encoded)| encoded.stringify_encoded())),
+                expected: Ok(Some(String::from("us%20er@")))
+            },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        let tests = [
+            TestCase {
+                case: "user@host:80".parse::<Authority>(),
+                expected: Ok(Authority {
+                    userinfo: Some(String::from("user")),
+                    host: Some(String::from("host")),
+                    port: Some(80),
+                }),
+            },
+            TestCase { case: "".parse::<Authority>(), expected: Err(Error::EmptyAuthority) },
+            TestCase { case: "@:".parse::<Authority>(), expected: Err(Error::EmptyAuthority) },
+            TestCase { case: "host:port".parse::<Authority>(), expected: Err(Error::ParsePortError) },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+    }
+
+    #[test]
+    fn eq_host_port() {
+        let authority = Authority::parse("user@example.com:8080").unwrap().unwrap();
+        assert_eq!(authority, ("example.com", Some(8080)));
+        assert_ne!(authority, ("example.com", None));
+        assert_ne!(authority, ("example.org", Some(8080)));
+        assert_eq!(Authority::parse("example.com").unwrap().unwrap(), ("example.com", None));
+        assert_ne!(Authority::parse(":8080").unwrap().unwrap(), ("", Some(8080)));
+    }
+
+    #[test]
+    fn is_ipv4_host() {
+        let tests = [
+            TestCase{ case: Authority::parse("1.2.3.4").unwrap().map(|auth| (auth.is_ipv4_host(), auth.host)), expected: Some((true, Some(String::from("1.2.3.4")))) },
+            TestCase{ case: Authority::parse("user@1.2.3.4:80").unwrap().map(|auth| (auth.is_ipv4_host(), auth.host)), expected: Some((true, Some(String::from("1.2.3.4")))) },
+            TestCase{ case: Authority::parse("1.2.3.4.com").unwrap().map(|auth| (auth.is_ipv4_host(), auth.host)), expected: Some((false, Some(String::from("1.2.3.4.com")))) },
+            TestCase{ case: Authority::parse("1.2.3.256").unwrap().map(|auth| (auth.is_ipv4_host(), auth.host)), expected: Some((false, Some(String::from("1.2.3.256")))) },
+            TestCase{ case: Authority::parse("[::1]").unwrap().map(|auth| (auth.is_ipv4_host(), auth.host)), expected: Some((false, Some(String::from("[::1]")))) },
+            TestCase{ case: Authority::parse(":80").unwrap().map(|auth| (auth.is_ipv4_host(), auth.host)), expected: Some((false, None)) },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+    }
+
+    #[test]
+    fn host_kind() {
+        let tests = [
+            TestCase{ case: Authority::parse("1.2.3.4:80").unwrap().and_then(|auth| auth.host_kind()), expected: Some(HostKind::IpV4) },
+            TestCase{ case: Authority::parse("[::1]").unwrap().and_then(|auth| auth.host_kind()), expected: Some(HostKind::IpV6) },
+            TestCase{ case: Authority::parse("[v1a.abc]").unwrap().and_then(|auth| auth.host_kind()), expected: Some(HostKind::IpVFuture) },
+            TestCase{ case: Authority::parse("1.2.3.4.com").unwrap().and_then(|auth| auth.host_kind()), expected: Some(HostKind::RegName) },
+            TestCase{ case: Authority::parse("user@:80").unwrap().and_then(|auth| auth.host_kind()), expected: None },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+
+        let first = Authority::parse("[::1]").unwrap().and_then(|auth| auth.host_kind()).unwrap();
+        let second = Authority::parse("[2001:db8::]").unwrap().and_then(|auth| auth.host_kind()).unwrap();
+        assert_eq!(first, second);
+
+        let mut kinds = HashSet::new();
+        kinds.insert(first);
+        kinds.insert(second);
+        assert_eq!(kinds.len(), 1);
+        assert!(kinds.contains(&HostKind::IpV6));
+    }
+
     #[test]
     fn parse_err() {
         let tests = [
             TestCase {
-                case: Authority::parse("user:@[2001:db8:3333:4444:5555:6666:7777:8888]").err().unwrap(),
+                case: Authority::parse("us[er@[2001:db8:3333:4444:5555:6666:7777:8888]").err().unwrap(),
                 expected: Error::UserinfoIllegalCharacter,
             },
+            TestCase {
+                case: Authority::parse("user@example.com]:8080").err().unwrap(),
+                expected: Error::IllegaHostDefinition,
+            },
+            TestCase {
+                case: Authority::parse("[::1:8080").err().unwrap(),
+                expected: Error::IllegaHostDefinition,
+            },
             TestCase {
                 case: Authority::parse("user#@example.com:8080").err().unwrap(),
                 expected: Error::UserinfoIllegalCharacter,
@@ -438,7 +819,7 @@ mod tests {
             },
             TestCase {
                 case: Authority::parse("user@example.com:80%50").err().unwrap(),
-                expected: Error::ParsePortError,
+                expected: Error::PortPercentEncoded,
             },
             TestCase {
                 case: Authority::parse("[2001:db8:3333:4444:5555:6666:7777::8888]").err().unwrap(),
@@ -452,6 +833,18 @@ mod tests {
                 case: Authority::parse("[vX.::]").err().unwrap(),
                 expected: Error::IllegalIPvFuture,
             },
+            TestCase {
+                case: Authority::parse("[v.abc]").err().unwrap(),
+                expected: Error::IllegalIPvFuture,
+            },
+            TestCase {
+                case: Authority::parse("[v7.]").err().unwrap(),
+                expected: Error::IllegalIPvFuture,
+            },
+            TestCase {
+                case: Authority::parse("[v]").err().unwrap(),
+                expected: Error::IllegaHostDefinition,
+            },
 
         ];
 
@@ -460,4 +853,28 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Config {
+            upstream: Authority,
+        }
+
+        let config = Config { upstream: Authority::parse("us%20er@example.com:8080").unwrap().unwrap() };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"upstream":"us%20er@example.com:8080"}"#);
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+        let tests = [
+            TestCase{ case: serde_json::from_str::<Authority>(r#""[::1]:80""#).ok(), expected: Authority::parse("[::1]:80").unwrap() },
+            TestCase{ case: serde_json::from_str::<Authority>(r#""""#).ok(), expected: Some(Authority::default()) },
+            TestCase{ case: serde_json::from_str::<Authority>(r#""a b""#).ok(), expected: None },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+        assert_eq!(serde_json::to_string(&Authority::default()).unwrap(), r#""""#);
+    }
 }
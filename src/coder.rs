@@ -1,3 +1,15 @@
+// the tests from before the pedantic lints were enabled keep their original style
+#![cfg_attr(
+    test,
+    allow(
+        clippy::explicit_iter_loop,
+        clippy::semicolon_if_nothing_returned,
+        clippy::too_many_lines,
+        clippy::useless_conversion,
+        clippy::vec_init_then_push
+    )
+)]
+
 use crate::err::Error;
 
 use crate::statics;
@@ -30,9 +42,8 @@ impl Decoder {
             return Ok(self.output.iter().collect());
         }
         while !self.input.is_empty() {
-            let char = match self.input.pop_front() {
-                None => unreachable!(),
-                Some(c) => c,
+            let Some(char) = self.input.pop_front() else {
+                unreachable!()
             };
 
             // if a percent endcoded character was found
@@ -64,7 +75,7 @@ impl Decoder {
                 // first hex value can max. 7 so char is in ASCII range
                 if int1 > 7 {
                     return Err(Error::IllegalPercentEncoding);
-                };
+                }
 
                 // transform two ints into one char
                 // max would be 7F -> 7 and 15 ->  127
@@ -113,47 +124,111 @@ impl Encoder {
         if self.finished {
             return Ok(self.output.iter().collect());
         }
-        while !self.input.is_empty() {
-            let char = match self.input.pop_front() {
-                None => unreachable!(),
-                Some(c) => c,
-            };
-            
-
-            if self.viable_chars.contains(&char) {
-                self.output.push(char);
-            } 
-            // if the character is not allowed try to encode it
-            else {
+        while let Some(char) = self.input.pop_front() {
+            self.push_encoded(char)?;
+        }
 
-                let dec = char as u8;
+        self.finished = true;
+        // return output as string
+        Ok(self.output.iter().collect())
+    }
 
-                // check character is a ASCII character
-                if dec > 127 {
-                    return Err(Error::IllegalCharacter);
-                }
+    // like 'encode', but a '%' followed by two hex digits is kept as an already encoded character
+    pub fn encode_preserving_escapes(&mut self) -> Result<String, Error> {
+        if self.finished {
+            return Ok(self.output.iter().collect());
+        }
+        while let Some(char) = self.input.pop_front() {
+            let is_escape = char == '%'
+                && self.input.len() >= 2
+                && statics::HEXDIG.contains(&self.input[0])
+                && statics::HEXDIG.contains(&self.input[1]);
 
-                let x:Vec<char> = format!("{:2X}", dec).chars().collect();
-                self.output.push('%');
-                self.output.push(match x.first(){
-                    // None case should be unreachable
-                    None => return Err(Error::IllegalPercentEncoding),
-                    Some(c) =>  *c
-                });
-                self.output.push(match x.last(){
-                    // None case should be unreachable
-                    None => return Err(Error::IllegalPercentEncoding),
-                    Some(c) =>  *c
-                });
+            if is_escape {
+                self.output.push(char);
+                self.output.extend(self.input.drain(..2));
+            } else {
+                self.push_encoded(char)?;
             }
-
         }
 
         self.finished = true;
-        // return output as string
         Ok(self.output.iter().collect())
     }
 
+    fn push_encoded(&mut self, char: char) -> Result<(), Error> {
+        if self.viable_chars.contains(&char) {
+            self.output.push(char);
+            return Ok(());
+        }
+
+        // if the character is not allowed try to encode it,
+        // only ASCII characters can be encoded
+        if !char.is_ascii() {
+            return Err(Error::IllegalCharacter);
+        }
+
+        let dec = char as u8;
+        let x:Vec<char> = format!("{dec:02X}").chars().collect();
+        self.output.push('%');
+        self.output.push(match x.first(){
+            // None case should be unreachable
+            None => return Err(Error::IllegalPercentEncoding),
+            Some(c) =>  *c
+        });
+        self.output.push(match x.last(){
+            // None case should be unreachable
+            None => return Err(Error::IllegalPercentEncoding),
+            Some(c) =>  *c
+        });
+        Ok(())
+    }
+
+}
+
+// decodes only percent encoded unreserved characters, all other escapes are kept as they are
+// RFC 3986 January 2005 6.2.2.2. Percent-Encoding Normalization
+pub fn decode_unreserved(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '%' && i + 2 < chars.len() {
+            if let (Some(int1), Some(int2)) = (chars[i + 1].to_digit(16), chars[i + 2].to_digit(16)) {
+                // two hex digits are guaranteed to fit into u8
+                #[allow(clippy::cast_possible_truncation)]
+                let decoded_char = (int1 * 16 + int2) as u8 as char;
+                if statics::UNRESERVED.contains(&decoded_char) {
+                    output.push(decoded_char);
+                    i += 3;
+                    continue;
+                }
+            }
+        }
+        output.push(chars[i]);
+        i += 1;
+    }
+    output
+}
+
+// decodes percent encoded unreserved characters and uppercases the hex digits of all other escapes
+// RFC 3986 January 2005 6.2.2.1. Case Normalization and 6.2.2.2. Percent-Encoding Normalization
+pub fn normalize_percent_encoding(input: &str) -> String {
+    let decoded = decode_unreserved(input);
+    let mut output = String::with_capacity(decoded.len());
+    let mut hex_left = 0;
+    for char in decoded.chars() {
+        if char == '%' {
+            hex_left = 2;
+            output.push(char);
+        } else if hex_left > 0 {
+            hex_left -= 1;
+            output.push(char.to_ascii_uppercase());
+        } else {
+            output.push(char);
+        }
+    }
+    output
 }
 
 #[test]
@@ -177,6 +252,15 @@ fn encoder_decode_ok() {
             },
             expected: Ok(String::from("Hello%20World%21")),
         },
+        TestCase {
+            case: {
+                let chars: Vec<char> = "\0\t".chars().into_iter().collect();
+                let mut encoder = Encoder::new(chars, &statics::ALPHA);
+                encoder.encode()
+
+            },
+            expected: Ok(String::from("%00%09")),
+        },
         TestCase {
             case: {
                 let chars: Vec<char> = " ".chars().into_iter().collect();
@@ -258,6 +342,15 @@ fn encoder_decode_err() {
             },
             expected: Err(Error::IllegalCharacter),
         },
+        TestCase {
+            case: {
+                let chars: Vec<char> = "\u{101}".chars().into_iter().collect();
+                let mut encoder = Encoder::new(chars, &statics::ALPHA);
+                encoder.encode()
+
+            },
+            expected: Err(Error::IllegalCharacter),
+        },
         
     ];
 
@@ -476,3 +569,50 @@ fn decoder_decode_err() {
         assert_eq!(test.case, test.expected);
     }
 }
+
+#[test]
+fn decode_unreserved_ok() {
+    let tests = [
+        TestCase { case: decode_unreserved("/%7Euser"), expected: String::from("/~user") },
+        TestCase { case: decode_unreserved("%41%2F%62"), expected: String::from("A%2Fb") },
+        TestCase { case: decode_unreserved("%2f%20"), expected: String::from("%2f%20") },
+        TestCase { case: decode_unreserved("%4"), expected: String::from("%4") },
+        TestCase { case: decode_unreserved("%+1"), expected: String::from("%+1") },
+    ];
+    for test in &tests {
+        assert_eq!(test.case, test.expected);
+    }
+}
+
+#[test]
+fn normalize_percent_encoding_ok() {
+    let tests = [
+        TestCase { case: normalize_percent_encoding("/%7euser"), expected: String::from("/~user") },
+        TestCase { case: normalize_percent_encoding("%2f%3a%41"), expected: String::from("%2F%3AA") },
+        TestCase { case: normalize_percent_encoding("a%2Fb"), expected: String::from("a%2Fb") },
+        TestCase { case: normalize_percent_encoding("abc"), expected: String::from("abc") },
+    ];
+    for test in &tests {
+        assert_eq!(test.case, test.expected);
+    }
+}
+
+#[test]
+fn encoder_encode_preserving_escapes() {
+    let encode = |input: &str| Encoder::new(input.chars().collect(), &statics::PATH).encode_preserving_escapes();
+
+    let tests = [
+        TestCase { case: encode("a%20b"), expected: Ok(String::from("a%20b")) },
+        TestCase { case: encode("a b%2f"), expected: Ok(String::from("a%20b%2f")) },
+        TestCase { case: encode("100%"), expected: Ok(String::from("100%25")) },
+        TestCase { case: encode("%%41"), expected: Ok(String::from("%25%41")) },
+        TestCase { case: encode("%4g"), expected: Ok(String::from("%254g")) },
+        TestCase { case: encode("ü%20"), expected: Err(Error::IllegalCharacter) },
+    ];
+    for test in &tests {
+        assert_eq!(test.case, test.expected);
+    }
+
+    // the plain encoder escapes every '%'
+    assert_eq!(Encoder::new("a%20b".chars().collect(), &statics::PATH).encode(), Ok(String::from("a%2520b")));
+}
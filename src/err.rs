@@ -1,6 +1,6 @@
 use std::fmt;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Error {
     EmptyScheme,
     EmptyAuthority,
@@ -18,10 +18,62 @@ pub enum Error {
     FragmentIllegalCharacter,
     IllegalCharacter,
     IllegalPercentEncoding,
+    QueryKeyAlreadyExists,
+    SchemeTooLong,
+    HostTooLong,
+    PathTooLong,
+    MissingHost,
+    AuthorityNotAllowed,
+    PathNotAbsolute,
+    UserinfoNotAllowed,
+    HostNotAscii,
+    NoPort,
+    TooManyQueryParams,
+    EmptyPathSegment,
+    PortPercentEncoded,
+    MissingAuthority,
+    NonAsciiInput,
+    NonCanonicalScheme,
+    QueryDeserializeError,
+    HttpUriError,
 }
 
 impl std::error::Error for Error {}
 
+impl Error {
+    /// Returns a hint how the input that caused this error could be fixed, if there is a common fix.
+    /// The input is only inspected, parsing behaves the same with or without suggestions.
+    #[must_use]
+    pub fn suggestion(&self, input: &str) -> Option<String> {
+        match self {
+            Self::EmptyScheme => match input.trim_start_matches(':') {
+                "" => Some(String::from("Add a scheme such as \"http\" before the ':'.")),
+                rest => Some(format!("Add a scheme such as \"http:\" before \"{rest}\".")),
+            },
+            Self::SchemeIllegalFirstCharacter if input.starts_with(char::is_whitespace) => Some(String::from(
+                "Remove the leading whitespace or parse in permissive mode.",
+            )),
+            Self::UserinfoIllegalCharacter
+            | Self::HostIllegalCharacter
+            | Self::PathIllegalCharacter
+            | Self::QueryIllegalCharacter
+            | Self::FragmentIllegalCharacter
+            | Self::IllegalCharacter
+                if input.contains(' ') =>
+            {
+                Some(String::from("Spaces have to be percent encoded as \"%20\"."))
+            }
+            Self::IllegalPercentEncoding => Some(String::from(
+                "A literal '%' has to be percent encoded as \"%25\".",
+            )),
+            Self::EmptyAuthority => Some(String::from(
+                "Add a host after \"//\" or remove the \"//\".",
+            )),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -43,6 +95,80 @@ impl fmt::Display for Error {
             Self::PathIllegalCharacter => write!(f, "Illegal character in Path."),
             Self::QueryIllegalCharacter => write!(f, "Illegal character in Query."),
             Self::FragmentIllegalCharacter => write!(f, "Illegal character in Fragment."),
+            Self::QueryKeyAlreadyExists => write!(f, "Query already contains the given key."),
+            Self::SchemeTooLong => write!(f, "Scheme exceeds the maximum length."),
+            Self::HostTooLong => write!(f, "Host exceeds the maximum length."),
+            Self::PathTooLong => write!(f, "Path exceeds the maximum length."),
+            Self::MissingHost => write!(f, "Scheme requires a Host."),
+            Self::AuthorityNotAllowed => write!(f, "Scheme doesn't allow an Authority."),
+            Self::UserinfoNotAllowed => write!(f, "Userinfo is not allowed."),
+            Self::MissingAuthority => write!(f, "URI has no Authority."),
+            Self::NonAsciiInput => write!(f, "URI contains characters that are not ASCII characters."),
+            Self::NonCanonicalScheme => write!(f, "Scheme contains uppercase letters."),
+            Self::QueryDeserializeError => write!(f, "Query doesn't match the type to deserialize."),
+            Self::HttpUriError => write!(f, "URI is not accepted by the http crate."),
+            Self::PortPercentEncoded => write!(f, "Port must not be percent-encoded."),
+            Self::EmptyPathSegment => write!(f, "Path contains an empty segment."),
+            Self::TooManyQueryParams => write!(f, "Query exceeds the maximum number of parameters."),
+            Self::NoPort => write!(f, "No port given and the Scheme has no default port."),
+            Self::HostNotAscii => write!(f, "Host contains characters that are not ASCII characters."),
+            Self::PathNotAbsolute => {
+                write!(f, "Path must be empty or start with '/' when an Authority is present.")
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use crate::{TestCase, Uri};
+
+    use std::collections::HashMap;
+
+    #[test]
+    fn error_suggestion() {
+        let suggest = |input: &str| Uri::parse(input).err().and_then(|err| err.suggestion(input));
+
+        let tests = [
+            TestCase { case: suggest("://x").map(|hint| hint.contains("before \"//x\"")), expected: Some(true) },
+            TestCase { case: suggest("http://x/a b").map(|hint| hint.contains("%20")), expected: Some(true) },
+            TestCase { case: suggest("http://x/a?b c").map(|hint| hint.contains("%20")), expected: Some(true) },
+            TestCase { case: suggest("http://x/100%").map(|hint| hint.contains("%25")), expected: Some(true) },
+            TestCase { case: suggest(" http://x").map(|hint| hint.contains("permissive")), expected: Some(true) },
+            TestCase { case: suggest("http://x/a").map(|hint| hint.is_empty()), expected: None },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+
+        assert_eq!(Error::MissingHost.suggestion("http:/p"), None);
+        assert_eq!(suggest("://x").as_deref(), Some("Add a scheme such as \"http:\" before \"//x\"."));
+        assert_eq!(suggest(":").as_deref(), Some("Add a scheme such as \"http\" before the ':'."));
+    }
+
+    #[test]
+    fn error_port_percent_encoded() {
+        let err = Uri::parse("http://example.com:80%50/").unwrap_err();
+        assert_eq!(err, Error::PortPercentEncoded);
+        assert_eq!(err.to_string(), "Port must not be percent-encoded.");
+        assert_eq!(Uri::parse("http://example.com:8a/").unwrap_err(), Error::ParsePortError);
+    }
+
+    #[test]
+    fn error_hash_count() {
+        let inputs = ["://x", "http://x/a b", "http://x/c d", "1http://x", "http://x/e f"];
+        let mut counts: HashMap<Error, usize> = HashMap::new();
+        for input in &inputs {
+            if let Err(err) = Uri::parse(input) {
+                *counts.entry(err).or_insert(0) += 1;
+            }
+        }
+
+        assert_eq!(counts.get(&Error::PathIllegalCharacter), Some(&3));
+        assert_eq!(counts.get(&Error::EmptyScheme), Some(&1));
+        assert_eq!(counts.get(&Error::SchemeIllegalFirstCharacter), Some(&1));
+        assert_eq!(counts.len(), 3);
+    }
+}
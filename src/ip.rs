@@ -1,76 +1,37 @@
-use std::collections::VecDeque;
+// the tests from before the pedantic lints were enabled keep their original style
+#![cfg_attr(test, allow(clippy::bool_assert_comparison))]
 
 use crate::statics;
 
-struct IPv6Parser {
-    had_double_colon: bool,
-    input: VecDeque<char>,
-    colon_counter: u8,
-    char_counter: u8,
-    max_colons: u8,
-}
+// an IPv6 address has 8 groups of 16 bits
+const IP_V6_GROUPS: usize = 8;
 
-impl IPv6Parser {
-    fn new(input: &str) -> Self {
-        let chars: Vec<char> = input.chars().into_iter().collect();
-        IPv6Parser {
-            had_double_colon: false,
-            input: VecDeque::from(chars),
-            colon_counter: 0,
-            char_counter: 0,
-            max_colons: 7,
-        }
+// counts the groups of one side of a "::", None if a group is not 1 to 4 hexdigits
+fn count_ip_v6_groups(input: &str) -> Option<usize> {
+    if input.is_empty() {
+        return Some(0);
     }
-
-    fn is_valid(&mut self) -> bool {
-        if self.input.is_empty() {
-            return false;
-        }
-
-        let mut colon = false;
-        while !self.input.is_empty() {
-            let char = match self.input.pop_front() {
-                Some(c) => c,
-                None => return false,
-            };
-
-            if char == ':' {
-                // max number of colons was already reached
-                if self.colon_counter >= self.max_colons {
-                    return false;
-                }
-
-                // if the last character was a ":"
-                if colon {
-                    // double colon multiple times
-                    if self.had_double_colon {
-                        return false;
-                    }
-                    self.had_double_colon = true;
-                    self.colon_counter += 1;
-                } else {
-                    colon = true;
-                    self.colon_counter += 1;
-                    self.char_counter = 0;
-                }
-            }
-            // character is not a hexdigit
-            else {
-                // max 4 hexdigits in one segment
-                if self.char_counter > 4 {
-                    return false;
-                }
-                if !statics::HEXDIG.contains(&char) {
-                    return false;
-                }
-                colon = false;
-                self.char_counter += 1;
-            }
+    let mut count = 0;
+    for group in input.split(':') {
+        // h16 = 1*4HEXDIG
+        if group.is_empty() || group.len() > 4 || !group.chars().all(|c| statics::HEXDIG.contains(&c)) {
+            return None;
         }
+        count += 1;
+    }
+    Some(count)
+}
 
-        // check if ip is too short
-        self.max_colons <= self.colon_counter || self.had_double_colon
-        
+fn is_valid_ip_v6_groups(input: &str) -> bool {
+    // "::" replaces one or more groups of zeros and may only appear once
+    let sides: Vec<&str> = input.split("::").collect();
+    match sides.as_slice() {
+        [groups] => count_ip_v6_groups(groups) == Some(IP_V6_GROUPS),
+        [before, after] => match (count_ip_v6_groups(before), count_ip_v6_groups(after)) {
+            (Some(before), Some(after)) => before + after < IP_V6_GROUPS,
+            (_, _) => false,
+        },
+        _ => false,
     }
 }
 
@@ -78,31 +39,19 @@ impl IPv6Parser {
 
 pub fn is_valid_ip_v_future(input: &str) -> bool {
     // "v" 1*HEXDIG "." 1*( unreserved / sub-delims / ":" )
-    if input.starts_with('v') || input.starts_with('V') {
-        let mut chars = input.chars();
-        // get second and third character
-        // warning: chars.nth() uses next()
-        let (hexdig, dot) = match (chars.nth(1), chars.next()) {
-            (Some(c), Some(d)) => (c, d),
-            (_, _) => return false,
-        };
-        // if second character is a hexdigit and third character a "." and contains no percent encoded character
-        if statics::HEXDIG.contains(&hexdig) && dot == '.' && !input.contains('%') {
-            // check if every char is valid
-            for char in input[3..].chars() {
-                if !statics::STRIPPED_IP_FUTURE.contains(&char) {
-                    return false;
-                }
-            }
-            true
-        } else {
-            false
-        }
-    }
     // doesn't start with a "v"
-    else {
-        false
-    }
+    let Some(rest) = input.strip_prefix('v').or_else(|| input.strip_prefix('V')) else {
+        return false;
+    };
+    // the version ends at the first ".", the address follows it
+    let Some((version, address)) = rest.split_once('.') else {
+        return false;
+    };
+
+    let valid_version = !version.is_empty() && version.chars().all(|c| statics::HEXDIG.contains(&c));
+    // no percent encoded characters are allowed, '%' is not in the character set
+    let valid_address = !address.is_empty() && address.chars().all(|c| statics::STRIPPED_IP_FUTURE.contains(&c));
+    valid_version && valid_address
 }
 
 #[test]
@@ -112,13 +61,37 @@ fn is_valid_ip_v_future_test() {
     assert_eq!(is_valid_ip_v_future("x7.abc"), false);
     assert_eq!(is_valid_ip_v_future("xX.abc"), false);
     assert_eq!(is_valid_ip_v_future("V7:127.0.0.1"), false);
+    assert_eq!(is_valid_ip_v_future("v1a.abc"), true);
+    assert_eq!(is_valid_ip_v_future("vFF01.a:b"), true);
+    assert_eq!(is_valid_ip_v_future("v.abc"), false);
+    assert_eq!(is_valid_ip_v_future("v1g.abc"), false);
+    assert_eq!(is_valid_ip_v_future("v1."), false);
+    assert_eq!(is_valid_ip_v_future("v1a"), false);
+    assert_eq!(is_valid_ip_v_future("v1.a%20"), false);
+}
+
+#[test]
+fn is_valid_ip_v_future_short_input_test() {
+    for input in &["", "v", "V", "v7", "v.", "V.", "v7.", ".", "7.a", "vü.a", "v7.ü"] {
+        assert!(!is_valid_ip_v_future(input), "{}", input);
+    }
 }
 
 //  ###########################
 
 pub fn is_valid_ip_v6(input: &str) -> bool {
-    let mut parser = IPv6Parser::new(input);
-    parser.is_valid()
+    // an IPv4 address after the last ':' takes the place of the last two groups ("::ffff:192.168.1.1")
+    if let Some(last_colon) = input.rfind(':') {
+        let (groups, ip_v4) = input.split_at(last_colon + 1);
+        if ip_v4.contains('.') {
+            if !is_valid_ip_v4(ip_v4) {
+                return false;
+            }
+            return is_valid_ip_v6_groups(&format!("{groups}0:0"));
+        }
+    }
+
+    is_valid_ip_v6_groups(input)
 }
 
 #[test]
@@ -145,6 +118,74 @@ fn is_valid_ip_v6_test() {
     assert_eq!(is_valid_ip_v6("200:db8:333:AAA:BBB:CCC:DDD:EEE:FFF"), false);
     assert_eq!(is_valid_ip_v6("200:db8:333::AAA:BBB:CCC:DDD:EEE"), false);
     assert_eq!(is_valid_ip_v6("200:db8:333:AAA:BBB:CCC:DDD:EEE::"), false);
+    assert_eq!(is_valid_ip_v6("::ffff:192.168.1.1"), true);
+    assert_eq!(is_valid_ip_v6("::1.2.3.4"), true);
+    assert_eq!(is_valid_ip_v6("1:2:3:4:5:6:1.2.3.4"), true);
+    assert_eq!(is_valid_ip_v6("1:2:3:4:5:6:7:1.2.3.4"), false);
+    assert_eq!(is_valid_ip_v6("::ffff:192.168.1.256"), false);
+    assert_eq!(is_valid_ip_v6("::ffff:1.2.3"), false);
+    assert_eq!(is_valid_ip_v6("1.2.3.4"), false);
+}
+
+#[test]
+fn is_valid_ip_v6_group_count_test() {
+    // without "::" exactly 8 groups
+    assert!(is_valid_ip_v6("1:2:3:4:5:6:7:8"));
+    assert!(!is_valid_ip_v6("1:2:3:4:5:6:7:8:9"));
+    assert!(!is_valid_ip_v6("1:2:3:4:5:6:7"));
+    assert!(!is_valid_ip_v6("1"));
+    // with "::" at most 7 groups, wherever the "::" is
+    assert!(is_valid_ip_v6("::1:2:3:4:5:6:7"));
+    assert!(is_valid_ip_v6("1:2:3:4:5:6:7::"));
+    assert!(is_valid_ip_v6("1:2:3::4:5:6:7"));
+    assert!(!is_valid_ip_v6("::1:2:3:4:5:6:7:8"));
+    assert!(!is_valid_ip_v6("1:2:3:4:5:6:7:8::"));
+    assert!(!is_valid_ip_v6("1:2:3:4::5:6:7:8"));
+    assert!(is_valid_ip_v6("1::"));
+    assert!(is_valid_ip_v6("::1"));
+    // empty groups outside of "::"
+    assert!(!is_valid_ip_v6(":1:2:3:4:5:6:7:8"));
+    assert!(!is_valid_ip_v6("1:2:3:4:5:6:7:8:"));
+    assert!(!is_valid_ip_v6("1::2:"));
+    assert!(!is_valid_ip_v6(":::"));
+    assert!(!is_valid_ip_v6("1::2::3"));
+    assert!(!is_valid_ip_v6(""));
+    // 1 to 4 hexdigits per group
+    assert!(!is_valid_ip_v6("12345::"));
+    assert!(is_valid_ip_v6("1:2:3:4:5:6:7:abcd"));
+    // an IPv4 suffix counts as two groups
+    assert!(is_valid_ip_v6("1:2:3:4:5::1.2.3.4"));
+    assert!(!is_valid_ip_v6("1:2:3:4:5:6::1.2.3.4"));
+    assert!(is_valid_ip_v6("::1:2:3:4:5:1.2.3.4"));
+    assert!(!is_valid_ip_v6("::1:2:3:4:5:6:1.2.3.4"));
+}
+
+//  ###########################
+
+pub fn is_valid_ip_v4(input: &str) -> bool {
+    // dec-octet "." dec-octet "." dec-octet "." dec-octet
+    let octets: Vec<&str> = input.split('.').collect();
+    octets.len() == 4 && octets.iter().all(|octet| is_valid_dec_octet(octet))
+}
+
+fn is_valid_dec_octet(input: &str) -> bool {
+    // "0" to "255" without leading zeros
+    let valid_chars = !input.is_empty() && input.len() <= 3 && input.chars().all(|c| statics::DIGIT.contains(&c));
+    let leading_zero = input.len() > 1 && input.starts_with('0');
+    valid_chars && !leading_zero && input.parse::<u8>().is_ok()
+}
+
+#[test]
+fn is_valid_ip_v4_test() {
+    assert!(is_valid_ip_v4("192.168.1.1"));
+    assert!(is_valid_ip_v4("0.0.0.0"));
+    assert!(is_valid_ip_v4("255.255.255.255"));
+    assert!(!is_valid_ip_v4("256.1.1.1"));
+    assert!(!is_valid_ip_v4("01.1.1.1"));
+    assert!(!is_valid_ip_v4("1.1.1"));
+    assert!(!is_valid_ip_v4("1.1.1.1.1"));
+    assert!(!is_valid_ip_v4("1..1.1"));
+    assert!(!is_valid_ip_v4("+1.1.1.1"));
 }
 
 //  ###########################
@@ -4,17 +4,22 @@ mod authority;
 mod coder;
 mod err;
 mod ip;
+mod options;
+mod querys;
 mod statics;
+mod tel;
 mod uri;
 
 #[macro_use]
 extern crate lazy_static;
 
 pub use crate::{
-    uri::Uri,
-    authority::Authority,
+    uri::{Component, ComponentDiff, ParsedParts, PathKind, Uri, UriComponents, UriOffsets, Warning},
+    authority::{Authority, HostKind},
     err::Error,
-    
+    options::{Mode, ParseOptions},
+    querys::Querys,
+    tel::TelUri,
 };
 
 #[cfg(test)]
@@ -0,0 +1,48 @@
+/// Options to change the behaviour of `Uri::parse_with_options`.
+/// The default options parse like `Uri::parse`.
+// every flag switches an independent check, a state machine doesn't fit here
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParseOptions {
+    /// Decode a literal '+' in the path as a space (" ").
+    /// A percent encoded plus ("%2B") is still decoded as '+'.
+    pub plus_is_space_in_path: bool,
+
+    /// Maximum number of characters in the scheme, `None` for no limit.
+    pub max_scheme_length: Option<usize>,
+
+    /// Maximum number of characters in the (still encoded) host, `None` for no limit.
+    /// 255 is the maximum length of a domain name.
+    pub max_host_length: Option<usize>,
+
+    /// Maximum number of characters in the (still encoded) path, `None` for no limit.
+    pub max_path_length: Option<usize>,
+
+    /// Return `Error::UserinfoNotAllowed` if the authority contains a userinfo.
+    pub forbid_userinfo: bool,
+
+    /// Maximum number of '&' separated pairs in the query, `None` for no limit.
+    pub max_query_params: Option<usize>,
+
+    /// Return `Error::ParsePortError` for port 0, which is valid syntax but can't be connected to.
+    pub forbid_port_zero: bool,
+
+    /// Return `Error::EmptyPathSegment` if the path contains two consecutive '/' ("/a//b").
+    pub forbid_empty_path_segments: bool,
+
+    /// Return `Error::NonCanonicalScheme` if the scheme contains uppercase letters ("HTTP"),
+    /// instead of lowercasing it.
+    pub reject_uppercase_scheme: bool,
+}
+
+/// How strict `Uri::parse_with_mode` treats its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Only accept URIs as described in RFC 3986, same as `Uri::parse`.
+    Rfc3986,
+    /// Clean up the input like a browser before parsing it strictly:
+    /// leading and trailing C0 control characters and spaces are removed,
+    /// tabs and newlines ('\\t', '\\n', '\\r') are removed everywhere,
+    /// in URIs with a special scheme ("http", "file", ...) every '\\' before the query is read as '/'.
+    Permissive,
+}
@@ -1,6 +1,15 @@
+use std::collections::{hash_map::Entry, HashMap};
+use std::fmt;
+
+use crate::{coder::Encoder, err::Error, statics};
+
+#[cfg(test)]
+use crate::TestCase;
+
+// every key holds its values in the order they were inserted, 'insert' only allows one value
 #[derive(Debug)]
 pub struct Querys {
-    content: HashMap<String, String>,
+    content: HashMap<String, Vec<String>>,
 }
 
 impl PartialEq for Querys {
@@ -15,13 +24,13 @@ impl PartialEq for Querys {
                     if self.content.get(key).unwrap() != other.content.get(key).unwrap() {
                         // both have the same key, but associated value is different
                         return false;
-                    };
+                    }
                 } else {
                     // missing one entry key makes them different
                     return false;
-                };
+                }
             }
-        };
+        }
         // both have the same length but are empty is guaranteed equal
         true
     }
@@ -37,13 +46,43 @@ impl Querys {
     #[must_use = "You wanted it, so take it!"]
     pub fn new() -> Self {
         Querys {
-            content: HashMap::<String, String>::new(),
+            content: HashMap::<String, Vec<String>>::new(),
+        }
+    }
+
+    /// Builds the entries from "key=value" pairs, a repeated key keeps all its values like `insert_multi`.
+    #[must_use = "You wanted it, so take it!"]
+    pub fn from_pairs(pairs: Vec<(String, String)>) -> Self {
+        let mut querys = Querys::new();
+        for (key, value) in pairs {
+            querys.insert_multi(key, value);
         }
+        querys
     }
 
+    /// Returns all "key=value" pairs sorted by key, the values of a key in the order they were inserted.
+    #[must_use = "You wanted it, so take it!"]
+    pub fn into_pairs(self) -> Vec<(String, String)> {
+        let mut entries: Vec<(String, Vec<String>)> = self.content.into_iter().collect();
+        entries.sort();
+
+        let mut pairs = Vec::new();
+        for (key, values) in entries {
+            pairs.extend(values.into_iter().map(|value| (key.clone(), value)));
+        }
+        pairs
+    }
+
+    /// Returns the first value of the given key.
     #[must_use = "You wanted it, so take it!"]
     pub fn get(&self, key: &str) -> Option<&String> {
-        self.content.get(key)
+        self.content.get(key).and_then(|values| values.first())
+    }
+
+    /// Returns all values of the given key in the order they were inserted.
+    #[must_use = "You wanted it, so take it!"]
+    pub fn get_all(&self, key: &str) -> Option<&[String]> {
+        self.content.get(key).map(Vec::as_slice)
     }
 
     /// # Errors
@@ -52,17 +91,79 @@ impl Querys {
     /// To avoid unintended behavior this method will return an Error.
     pub fn insert(&mut self, key: String, value: String) -> Result<(), Error> {
         if let Entry::Vacant(e) = self.content.entry(key) {
-            e.insert(value);
+            e.insert(vec![value]);
             Ok(())
         } else {
             Err(Error::QueryKeyAlreadyExists)
         }
     }
+
+    /// Adds a value to the given key, a key that already exists keeps its values, like "a=1&a=2".
+    pub fn insert_multi(&mut self, key: String, value: String) {
+        self.content.entry(key).or_default().push(value);
+    }
+
+    /// Returns the entries as a query string ("a=1&b=2") sorted by key, keys and values are percent encoded.
+    ///
+    /// # Errors
+    ///
+    /// Will return 'Error' if a key or value contains a character that is not an ASCII character.
+    pub fn to_query_string(&self) -> Result<String, Error> {
+        let mut entries: Vec<(&String, &Vec<String>)> = self.content.iter().collect();
+        entries.sort();
+
+        let mut pairs = Vec::with_capacity(entries.len());
+        for (key, values) in entries {
+            let encoded_key = Encoder::new(key.chars().collect(), &statics::QUERY_PAIR).encode()?;
+            for value in values {
+                let encoded_value = Encoder::new(value.chars().collect(), &statics::QUERY_PAIR).encode()?;
+                pairs.push(format!("{encoded_key}={encoded_value}"));
+            }
+        }
+        Ok(pairs.join("&"))
+    }
+}
+
+// displays the query string, a key or value that can't be encoded is a 'fmt::Error'
+impl fmt::Display for Querys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let query_string = self.to_query_string().map_err(|_| fmt::Error)?;
+        write!(f, "{query_string}")
+    }
+}
+
+// a map of every key to all its values, the keys sorted
+#[cfg(feature = "serde")]
+impl serde::Serialize for Querys {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut entries: Vec<(&String, &Vec<String>)> = self.content.iter().collect();
+        entries.sort();
+
+        let mut map = serializer.serialize_map(Some(entries.len()))?;
+        for (key, values) in entries {
+            map.serialize_entry(key, values)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Querys {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let content = <HashMap<String, Vec<String>> as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(Querys { content })
+    }
 }
 
 #[cfg(test)]
+// the tests from before the pedantic lints were enabled keep their original style
+#[allow(unused_must_use, clippy::explicit_iter_loop)]
 mod querys_test {
     use super::{HashMap, Querys, TestCase};
+    use crate::Error;
+    use std::fmt::Write;
 
     // Test Case with No Querys
     // both no query -> equal
@@ -71,10 +172,10 @@ mod querys_test {
         HashMap::<String, String>::new();
         let test = TestCase {
             case: Querys {
-                content: HashMap::<String, String>::new(),
+                content: HashMap::<String, Vec<String>>::new(),
             },
             expected: Querys {
-                content: HashMap::<String, String>::new(),
+                content: HashMap::<String, Vec<String>>::new(),
             },
         };
         assert_eq!(test.case, test.expected);
@@ -89,25 +190,25 @@ mod querys_test {
             TestCase {
                 case: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
                         map
                     },
                 },
                 expected: Querys {
-                    content: HashMap::<String, String>::new(),
+                    content: HashMap::<String, Vec<String>>::new(),
                 },
             },
             TestCase {
                 expected: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
                         map
                     },
                 },
                 case: Querys {
-                    content: HashMap::<String, String>::new(),
+                    content: HashMap::<String, Vec<String>>::new(),
                 },
             },
         ];
@@ -124,15 +225,15 @@ mod querys_test {
             TestCase {
                 case: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
                         map
                     },
                 },
                 expected: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
                         map
                     },
                 },
@@ -140,15 +241,15 @@ mod querys_test {
             TestCase {
                 expected: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
                         map
                     },
                 },
                 case: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
                         map
                     },
                 },
@@ -167,15 +268,15 @@ mod querys_test {
             TestCase {
                 case: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("peter"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("peter")]);
                         map
                     },
                 },
                 expected: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
                         map
                     },
                 },
@@ -183,15 +284,15 @@ mod querys_test {
             TestCase {
                 expected: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("age"), String::from("5"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("age"), vec![String::from("5")]);
                         map
                     },
                 },
                 case: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("age"), String::from("10"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("age"), vec![String::from("10")]);
                         map
                     },
                 },
@@ -210,17 +311,17 @@ mod querys_test {
             TestCase {
                 case: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
-                        map.insert(String::from("age"), String::from("10"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
+                        map.insert(String::from("age"), vec![String::from("10")]);
                         map
                     },
                 },
                 expected: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
-                        map.insert(String::from("age"), String::from("10"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
+                        map.insert(String::from("age"), vec![String::from("10")]);
                         map
                     },
                 },
@@ -228,17 +329,17 @@ mod querys_test {
             TestCase {
                 expected: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
-                        map.insert(String::from("age"), String::from("10"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
+                        map.insert(String::from("age"), vec![String::from("10")]);
                         map
                     },
                 },
                 case: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
-                        map.insert(String::from("age"), String::from("10"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
+                        map.insert(String::from("age"), vec![String::from("10")]);
                         map
                     },
                 },
@@ -257,17 +358,17 @@ mod querys_test {
             TestCase {
                 case: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
-                        map.insert(String::from("age"), String::from("10"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
+                        map.insert(String::from("age"), vec![String::from("10")]);
                         map
                     },
                 },
                 expected: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("peter"));
-                        map.insert(String::from("age"), String::from("5"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("peter")]);
+                        map.insert(String::from("age"), vec![String::from("5")]);
                         map
                     },
                 },
@@ -275,17 +376,17 @@ mod querys_test {
             TestCase {
                 expected: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("peter"));
-                        map.insert(String::from("age"), String::from("5"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("peter")]);
+                        map.insert(String::from("age"), vec![String::from("5")]);
                         map
                     },
                 },
                 case: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
-                        map.insert(String::from("age"), String::from("10"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
+                        map.insert(String::from("age"), vec![String::from("10")]);
                         map
                     },
                 },
@@ -305,17 +406,17 @@ mod querys_test {
             TestCase {
                 case: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("nama"), String::from("bob"));
-                        map.insert(String::from("age"), String::from("10"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("nama"), vec![String::from("bob")]);
+                        map.insert(String::from("age"), vec![String::from("10")]);
                         map
                     },
                 },
                 expected: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("peter"));
-                        map.insert(String::from("age"), String::from("5"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("peter")]);
+                        map.insert(String::from("age"), vec![String::from("5")]);
                         map
                     },
                 },
@@ -323,17 +424,17 @@ mod querys_test {
             TestCase {
                 expected: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("peter"));
-                        map.insert(String::from("agu"), String::from("5"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("peter")]);
+                        map.insert(String::from("agu"), vec![String::from("5")]);
                         map
                     },
                 },
                 case: Querys {
                     content: {
-                        let mut map = HashMap::<String, String>::new();
-                        map.insert(String::from("name"), String::from("bob"));
-                        map.insert(String::from("age"), String::from("10"));
+                        let mut map = HashMap::<String, Vec<String>>::new();
+                        map.insert(String::from("name"), vec![String::from("bob")]);
+                        map.insert(String::from("age"), vec![String::from("10")]);
                         map
                     },
                 },
@@ -344,4 +445,92 @@ mod querys_test {
             assert_ne!(test.case, test.expected);
         }
     }
+
+    #[test]
+    fn querys_to_query_string() {
+        let mut querys = Querys::new();
+        querys.insert(String::from("name"), String::from("bob")).unwrap();
+        querys.insert(String::from("age"), String::from("42")).unwrap();
+
+        let mut encoded = Querys::new();
+        encoded.insert(String::from("a b"), String::from("x&y=z")).unwrap();
+
+        let mut non_ascii = Querys::new();
+        non_ascii.insert(String::from("k"), String::from("ü")).unwrap();
+
+        let tests = [
+            TestCase{ case: querys.to_query_string(), expected: Ok(String::from("age=42&name=bob")) },
+            TestCase{ case: encoded.to_query_string(), expected: Ok(String::from("a%20b=x%26y%3Dz")) },
+            TestCase{ case: Querys::new().to_query_string(), expected: Ok(String::new()) },
+            TestCase{ case: non_ascii.to_query_string(), expected: Err(Error::IllegalCharacter) },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+    }
+
+    #[test]
+    fn querys_display() {
+        let mut querys = Querys::new();
+        querys.insert(String::from("name"), String::from("bob")).unwrap();
+        querys.insert(String::from("age"), String::from("42")).unwrap();
+        assert_eq!(querys.to_string(), "age=42&name=bob");
+
+        let mut non_ascii = Querys::new();
+        non_ascii.insert(String::from("k"), String::from("ü")).unwrap();
+        assert!(write!(String::new(), "{non_ascii}").is_err());
+    }
+
+    #[test]
+    fn querys_insert_multi() {
+        let mut querys = Querys::new();
+        querys.insert(String::from("a"), String::from("1")).unwrap();
+        querys.insert_multi(String::from("a"), String::from("2"));
+        querys.insert_multi(String::from("b"), String::from("3"));
+
+        assert_eq!(querys.get("a"), Some(&String::from("1")));
+        assert_eq!(querys.get_all("a"), Some(&[String::from("1"), String::from("2")][..]));
+        assert_eq!(querys.get_all("c"), None);
+        assert_eq!(querys.insert(String::from("b"), String::from("4")), Err(Error::QueryKeyAlreadyExists));
+        assert_eq!(querys.to_string(), "a=1&a=2&b=3");
+    }
+
+    #[test]
+    fn querys_pairs() {
+        let pairs = vec![
+            (String::from("a"), String::from("1")),
+            (String::from("b"), String::from("2")),
+            (String::from("b"), String::from("3")),
+        ];
+        assert_eq!(Querys::from_pairs(pairs.clone()).into_pairs(), pairs);
+
+        let unsorted = vec![
+            (String::from("b"), String::from("3")),
+            (String::from("a"), String::from("1")),
+            (String::from("b"), String::from("2")),
+        ];
+        let sorted = vec![
+            (String::from("a"), String::from("1")),
+            (String::from("b"), String::from("3")),
+            (String::from("b"), String::from("2")),
+        ];
+        assert_eq!(Querys::from_pairs(unsorted).into_pairs(), sorted);
+        assert_eq!(Querys::from_pairs(Vec::new()), Querys::new());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn querys_serde_round_trip() {
+        let mut querys = Querys::new();
+        querys.insert_multi(String::from("b"), String::from("2"));
+        querys.insert_multi(String::from("a"), String::from("1"));
+        querys.insert_multi(String::from("a"), String::from("x y"));
+
+        let json = serde_json::to_string(&querys).unwrap();
+        assert_eq!(json, r#"{"a":["1","x y"],"b":["2"]}"#);
+        assert_eq!(serde_json::from_str::<Querys>(&json).unwrap(), querys);
+        assert_eq!(serde_json::from_str::<Querys>("{}").unwrap(), Querys::new());
+        assert!(serde_json::from_str::<Querys>(r#"{"a":"1"}"#).is_err());
+    }
 }
@@ -1,24 +1,25 @@
-use std::collections::HashSet;
+// the character sets are built with lazy_static, moving them to 'std::sync::LazyLock' is a change of its own
+#![allow(clippy::non_std_lazy_statics)]
+
+use std::collections::{HashMap, HashSet};
 
 // ALPHA contains all allowed letters
 lazy_static! {
     pub static ref ALPHA: HashSet<char> = ('a'..='z')
-        .into_iter()
-        .chain(('A'..='Z').into_iter())
+        .chain('A'..='Z')
         .collect::<HashSet<char>>();
 }
 
 // Digit contains all allowed digits
 lazy_static! {
-    pub static ref DIGIT: HashSet<char> = ('0'..='9').into_iter().collect::<HashSet<char>>();
+    pub static ref DIGIT: HashSet<char> = ('0'..='9').collect::<HashSet<char>>();
 }
 
 // HEXDIG contains all allowed hex chars
 lazy_static! {
     pub static ref HEXDIG: HashSet<char> = ('0'..='9')
-        .into_iter()
-        .chain(('a'..='f').into_iter())
-        .chain(('A'..='F').into_iter())
+        .chain('a'..='f')
+        .chain('A'..='F')
         .collect::<HashSet<char>>();
 }
 
@@ -69,11 +70,12 @@ lazy_static! {
 }
 
 // USER_INFO contains all characters that can be used in userinfo
-// UNRESERVED / SUB_DELIMS
+// UNRESERVED / SUB_DELIMS / ":"
 lazy_static! {
     pub static ref USER_INFO: HashSet<char> = UNRESERVED
         .iter()
         .chain(SUB_DELIMS.iter())
+        .chain([':'].iter())
         .copied()
         .collect::<HashSet<char>>();
 }
@@ -121,6 +123,17 @@ lazy_static! {
         .collect::<HashSet<char>>();
 }
 
+// SEGMENT contains all characters that can be used in a single path segment
+// UNRESERVED / SUB_DELIMS / ":" / "@"
+lazy_static! {
+    pub static ref SEGMENT: HashSet<char> = UNRESERVED
+        .iter()
+        .chain(SUB_DELIMS.iter())
+        .chain([':', '@'].iter())
+        .copied()
+        .collect::<HashSet<char>>();
+}
+
 // QUERY contains all characters that can be used in query
 // UNRESERVED / SUB_DELIMS / ":" / "@" / "/" / "?"
 lazy_static! {
@@ -132,6 +145,16 @@ lazy_static! {
         .collect::<HashSet<char>>();
 }
 
+// QUERY_PAIR contains all characters that can be used in a key or value of a query pair,
+// QUERY without the pair delimiters "&" / "=" and "+" which is often read as a space
+lazy_static! {
+    pub static ref QUERY_PAIR: HashSet<char> = QUERY
+        .iter()
+        .filter(|c| !['&', '=', '+'].contains(c))
+        .copied()
+        .collect::<HashSet<char>>();
+}
+
 // FRAGMENT contains all characters that can be used in fragment
 // UNRESERVED / SUB_DELIMS / ":" / "@" / "/" / "?"
 lazy_static! {
@@ -142,3 +165,38 @@ lazy_static! {
         .copied()
         .collect::<HashSet<char>>();
 }
+
+// FRAGMENT_PARSE contains all characters that are accepted in a fragment while parsing,
+// FRAGMENT and "#" because only the first "#" starts the fragment, "#" is encoded again when stringifying
+lazy_static! {
+    pub static ref FRAGMENT_PARSE: HashSet<char> = FRAGMENT
+        .iter()
+        .chain(['#'].iter())
+        .copied()
+        .collect::<HashSet<char>>();
+}
+
+// DEFAULT_PORTS maps lowercase schemes to the port that is used if no port is given
+lazy_static! {
+    pub static ref DEFAULT_PORTS: HashMap<&'static str, u16> = [
+        ("ftp", 21),
+        ("ssh", 22),
+        ("http", 80),
+        ("https", 443),
+        ("ws", 80),
+        ("wss", 443),
+        ("coap", 5683),
+        ("coaps", 5684),
+    ]
+    .iter()
+    .copied()
+    .collect::<HashMap<&'static str, u16>>();
+}
+
+// SPECIAL_SCHEMES contains the schemes the WHATWG URL standard treats special
+lazy_static! {
+    pub static ref SPECIAL_SCHEMES: HashSet<&'static str> = ["ftp", "file", "http", "https", "ws", "wss"]
+        .iter()
+        .copied()
+        .collect::<HashSet<&'static str>>();
+}
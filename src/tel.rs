@@ -0,0 +1,111 @@
+use crate::Querys;
+
+/// A telephone number of a "tel" URI as described in RFC 3966.
+#[derive(Debug, PartialEq)]
+pub struct TelUri {
+    number: String,
+    params: Querys,
+}
+
+impl TelUri {
+    // "+1-816-555-1212;ext=1234" -> number "+18165551212", params {ext: 1234}
+    pub(crate) fn parse(path: &str) -> Option<Self> {
+        let mut parts = path.split(';');
+        let raw_number = parts.next()?;
+
+        // remove visual separators, only a leading '+' and the digits are kept
+        // a global number only has DIGITs, a local one also HEXDIG, '*' and '#' (RFC 3966 3)
+        let global = raw_number.starts_with('+');
+        let mut number = String::new();
+        for (i, c) in raw_number.chars().enumerate() {
+            match c {
+                '+' if i == 0 => number.push(c),
+                '0'..='9' => number.push(c),
+                'a'..='f' | 'A'..='F' | '*' | '#' if !global => number.push(c),
+                '-' | '.' | '(' | ')' => {}
+                _ => return None,
+            }
+        }
+        if number.is_empty() || number == "+" {
+            return None;
+        }
+
+        let mut params = Querys::new();
+        for param in parts {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            params.insert(key.to_lowercase(), String::from(value)).ok()?;
+        }
+
+        Some(TelUri { number, params })
+    }
+
+    /// Returns the number without visual separators.
+    #[must_use]
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    /// Returns true if the number starts with '+' (RFC 3966 5.1.4).
+    #[must_use]
+    pub fn is_global(&self) -> bool {
+        self.number.starts_with('+')
+    }
+
+    #[must_use]
+    pub fn params(&self) -> &Querys {
+        &self.params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TelUri;
+    use crate::TestCase;
+
+    #[test]
+    fn tel_parse_ok() {
+        let tests = [
+            TestCase {
+                case: TelUri::parse("+1-816-555-1212").map(|tel| tel.number().to_owned()),
+                expected: Some(String::from("+18165551212")),
+            },
+            TestCase {
+                case: TelUri::parse("+1.(816).555.1212").map(|tel| tel.number().to_owned()),
+                expected: Some(String::from("+18165551212")),
+            },
+            TestCase {
+                case: TelUri::parse("7042;phone-context=example.com").map(|tel| tel.number().to_owned()),
+                expected: Some(String::from("7042")),
+            },
+            TestCase {
+                case: TelUri::parse("*23#a").map(|tel| tel.number().to_owned()),
+                expected: Some(String::from("*23#a")),
+            },
+            TestCase {
+                case: TelUri::parse("+1-816-555-1212;ext=1234").and_then(|tel| tel.params().get("ext").cloned()),
+                expected: Some(String::from("1234")),
+            },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+    }
+
+    #[test]
+    fn tel_parse_err() {
+        let tests = [
+            TestCase { case: TelUri::parse(""), expected: None },
+            TestCase { case: TelUri::parse("+"), expected: None },
+            TestCase { case: TelUri::parse("1+2"), expected: None },
+            TestCase { case: TelUri::parse("call me"), expected: None },
+            TestCase { case: TelUri::parse("+1;ext=1;ext=2"), expected: None },
+            TestCase { case: TelUri::parse("+abc"), expected: None },
+            TestCase { case: TelUri::parse("+1-800-*23"), expected: None },
+        ];
+
+        for test in &tests {
+            assert_eq!(test.case, test.expected);
+        }
+    }
+}